@@ -1,11 +1,24 @@
 use crate::{
-	empty_element, format_elements, group_elements, join_elements, space_token, FormatElement,
-	FormatResult, Formatter, ToFormatElement,
+	dynamic_token, empty_element, format_elements, group_elements, if_group_breaks, indent,
+	join_elements, soft_line_break_or_space, space_token, token, FormatElement, FormatResult,
+	Formatter, ToFormatElement,
 };
 use rslint_parser::ast::{
 	JsAnyObjectBindingPatternMember, JsObjectBindingPattern, JsObjectBindingPatternProperty,
 	JsObjectBindingPatternRest, JsObjectBindingPatternShorthandProperty,
 };
+use rslint_parser::{AstNode, SyntaxNode};
+
+/// Formats `node` by emitting its original source text verbatim, byte for byte, rather than
+/// re-printing it structurally. This is the fallback for parser recovery nodes
+/// (`JS_UNKNOWN_*`/`ERROR`) that don't have a sensible AST shape to format, so the formatter
+/// stays total over partially-invalid input instead of panicking. Slicing `node.text()` directly
+/// (instead of re-assembling `formatter.format_token` over each token) is what keeps the
+/// original inter-token whitespace and trivia intact, since per-token formatting only reproduces
+/// the token itself, not the bytes between tokens.
+pub(crate) fn format_verbatim_node(node: &SyntaxNode) -> FormatResult<FormatElement> {
+	Ok(dynamic_token(node.text().to_string()))
+}
 
 impl ToFormatElement for JsObjectBindingPattern {
 	fn to_format_element(&self, formatter: &Formatter) -> FormatResult<FormatElement> {
@@ -13,11 +26,33 @@ impl ToFormatElement for JsObjectBindingPattern {
 		let properties = formatter.format_separated(self.properties())?;
 		let r_bracket = formatter.format_token(&self.r_curly_token()?)?;
 
+		if properties.is_empty() {
+			return Ok(format_elements![l_bracket, r_bracket]);
+		}
+
+		// A rest element can't be followed by a comma in an object binding pattern, so don't add
+		// one when the pattern ends in `...rest` even if the group breaks.
+		let has_trailing_rest = matches!(
+			self.properties().last(),
+			Some(Ok(JsAnyObjectBindingPatternMember::JsObjectBindingPatternRest(_)))
+		);
+		let trailing_comma = if has_trailing_rest {
+			empty_element()
+		} else {
+			if_group_breaks(token(","))
+		};
+
+		// Prints `{ a, b, c }` on one line when it fits, and otherwise breaks to one property
+		// per line, indented, with a trailing comma and a trailing soft line break before the
+		// closing `}` (the same behavior Prettier applies to object patterns).
 		Ok(format_elements![group_elements(format_elements![
 			l_bracket,
-			space_token(),
-			join_elements(space_token(), properties),
-			space_token(),
+			indent(format_elements![
+				soft_line_break_or_space(),
+				join_elements(soft_line_break_or_space(), properties),
+				trailing_comma,
+			]),
+			soft_line_break_or_space(),
 			r_bracket
 		])])
 	}
@@ -38,7 +73,12 @@ impl ToFormatElement for JsAnyObjectBindingPatternMember {
 			JsAnyObjectBindingPatternMember::JsIdentifierBinding(identifier_binding) => {
 				identifier_binding.to_format_element(formatter)
 			}
-			JsAnyObjectBindingPatternMember::JsUnknownBinding(_) => todo!(),
+			// `JS_UNKNOWN_BINDING` is a recovery node the parser produces for a binding it
+			// couldn't make sense of (see `parse_parameters_list`), so rather than panicking we
+			// fall back to printing its source text verbatim.
+			JsAnyObjectBindingPatternMember::JsUnknownBinding(unknown_binding) => {
+				format_verbatim_node(unknown_binding.syntax())
+			}
 		}
 	}
 }
@@ -1,7 +1,7 @@
 //! Class and function declarations.
 
 use super::binding::parse_binding_pattern;
-use super::expr::expr_or_assignment;
+use super::expr::{expr_or_assignment, lhs_expr};
 use super::typescript::*;
 #[allow(deprecated)]
 use crate::parser::ParsedSyntax::{Absent, Present};
@@ -10,10 +10,65 @@ use crate::syntax::binding::parse_binding_pattern_with_optional_default;
 use crate::syntax::function::function_body;
 use crate::syntax::js_parse_error;
 use crate::syntax::js_parse_error::expected_binding;
+use crate::JsSyntaxFeature::Decorators;
 use crate::{SyntaxKind::*, *};
 
+// test ts_decorator_parameter
+// class Foo {
+//   constructor(@Inject() private dep) {}
+// }
+/// Parses a run of decorators (`@foo @bar.baz()`) into a `TS_DECORATOR_LIST`. Decorators are
+/// always parsed so that formatting and recovery keep working, but they're only *semantically*
+/// valid when the `Decorators` syntax feature is enabled; when it isn't, each decorator is kept
+/// in the tree and flagged with a diagnostic instead, mirroring how TypeScript-only constructs
+/// are handled elsewhere in this parser.
+///
+/// Only wired into parameter lists and [parse_function] so far — class declarations have no
+/// parsing entry point in this module, so `@Injectable() class Foo {}` doesn't get a
+/// `TS_DECORATOR_LIST` yet.
+pub(super) fn decorators(p: &mut Parser) -> ParsedSyntax<CompletedMarker> {
+	if !p.at(T![@]) {
+		return Absent;
+	}
+
+	let list = p.start();
+	let mut progress = ParserProgress::default();
+
+	while p.at(T![@]) {
+		progress.assert_progressing(p);
+		decorator(p).or_missing(p);
+	}
+
+	Present(list.complete(p, TS_DECORATOR_LIST))
+}
+
+fn decorator(p: &mut Parser) -> ParsedSyntax<CompletedMarker> {
+	Decorators.parse_exclusive_syntax(
+		p,
+		|p| {
+			let m = p.start();
+			p.expect_required(T![@]);
+			lhs_expr(p).or_missing_with_error(p, js_parse_error::expected_expression);
+			Present(m.complete(p, TS_DECORATOR))
+		},
+		|p, marker| {
+			p.err_builder("decorators are not allowed")
+				.primary(marker.range(p), "")
+		},
+	)
+}
+
 #[allow(clippy::unnecessary_unwrap)]
 pub(super) fn parse_formal_param_pat(p: &mut Parser) -> ParsedSyntax<CompletedMarker> {
+	// Opened before `decorators` runs so that, when a decorator is present, it ends up nested
+	// as a child of the completed parameter node below rather than as a preceding sibling of it.
+	let m = p.start();
+	let decorator_list = decorators(p);
+
+	if p.typescript() && p.state.in_constructor && at_parameter_property_modifier(p) {
+		return parse_ts_property_parameter(p, m);
+	}
+
 	if p.typescript() {
 		if let Some(modifier) = maybe_eat_incorrect_modifier(p) {
 			let err = p
@@ -24,7 +79,60 @@ pub(super) fn parse_formal_param_pat(p: &mut Parser) -> ParsedSyntax<CompletedMa
 		}
 	}
 
-	parse_binding_pattern_with_optional_default(p)
+	let binding = parse_binding_pattern_with_optional_default(p);
+
+	if decorator_list.is_absent() {
+		m.abandon(p);
+		return binding;
+	}
+
+	binding.or_missing_with_error(p, expected_binding);
+	Present(m.complete(p, TS_PROPERTY_PARAMETER))
+}
+
+/// Whether the current token starts a constructor parameter-property modifier
+/// (`public` / `private` / `protected` / `readonly` / `override`), without consuming it.
+/// A modifier keyword with no binding after it (`constructor(public) {}`) does not start a
+/// parameter property — only treat the keyword as a modifier when a binding (an identifier,
+/// another modifier to chain like `public readonly x`, `{`, or `[`) still follows it.
+fn at_parameter_property_modifier(p: &mut Parser) -> bool {
+	if !is_property_modifier_token(p, 0) {
+		return false;
+	}
+
+	is_property_modifier_token(p, 1) || matches!(p.nth(1), T![ident] | T!['{'] | T!['['])
+}
+
+fn is_property_modifier_token(p: &mut Parser, n: usize) -> bool {
+	matches!(p.nth(n), T![public] | T![private] | T![protected] | T![readonly]) || p.nth_src(n) == "override"
+}
+
+// test ts_property_parameter
+// class Foo {
+//   constructor(public a, private b, protected readonly c, override d) {}
+// }
+//
+// test_err ts_property_parameter_outside_constructor
+// class Foo {
+//   method(public a) {}
+// }
+/// Parses a constructor parameter that's prefixed with a run of accessibility modifiers
+/// (`public` / `private` / `protected` / `override`) and/or `readonly`, i.e. a TypeScript
+/// "parameter property", which declares and initializes a class field from the constructor
+/// argument of the same name. Only called once [at_parameter_property_modifier] has confirmed a
+/// modifier is actually present, so plain constructor parameters keep parsing as ordinary
+/// bindings. `m` is the marker the caller already opened before parsing any decorators, so those
+/// decorator nodes are completed as children of the `TS_PROPERTY_PARAMETER` produced here.
+fn parse_ts_property_parameter(p: &mut Parser, m: Marker) -> ParsedSyntax<CompletedMarker> {
+	let mut progress = ParserProgress::default();
+
+	while maybe_eat_incorrect_modifier(p).is_some() {
+		progress.assert_progressing(p);
+	}
+
+	parse_binding_pattern_with_optional_default(p).or_missing_with_error(p, expected_binding);
+
+	Present(m.complete(p, TS_PROPERTY_PARAMETER))
 }
 
 // test parameter_list
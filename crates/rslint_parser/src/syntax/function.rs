@@ -1,6 +1,6 @@
 use crate::parser::ParsedSyntax;
 use crate::syntax::binding::parse_binding;
-use crate::syntax::decl::parse_parameter_list;
+use crate::syntax::decl::{decorators, parse_parameter_list};
 use crate::syntax::js_parse_error;
 use crate::syntax::stmt::{is_semi, parse_block_impl};
 use crate::syntax::typescript::{ts_type_or_type_predicate_ann, ts_type_params};
@@ -52,6 +52,8 @@ pub(super) fn parse_function_expression(p: &mut Parser) -> ParsedSyntax<Conditio
 fn parse_function(p: &mut Parser, kind: SyntaxKind) -> ParsedSyntax<ConditionalSyntax> {
 	let m = p.start();
 
+	decorators(p).or_missing(p);
+
 	let mut uses_invalid_syntax =
 		kind == JS_FUNCTION_DECLARATION && p.eat(T![declare]) && TypeScript.is_unsupported(p);
 
@@ -166,9 +168,18 @@ pub(super) fn function_body_or_declaration(p: &mut Parser) {
 	}
 }
 
+// Function and method type parameters are not one of the positions TypeScript 4.7 allows
+// `in`/`out` variance modifiers in (those are only legal on type aliases, interfaces, and
+// classes), so both entry points here parse with `in_variance_position` turned off and rely on
+// `ts_type_params` to attach a diagnostic to any variance modifier it still consumes for
+// recovery.
 pub(crate) fn parse_ts_parameter_types(p: &mut Parser) -> ParsedSyntax<CompletedMarker> {
 	if p.at(T![<]) {
-		Present(ts_type_params(p).unwrap())
+		let mut guard = p.with_state(ParserState {
+			in_variance_position: false,
+			..p.state.clone()
+		});
+		Present(ts_type_params(&mut *guard).unwrap())
 	} else {
 		Absent
 	}
@@ -176,8 +187,12 @@ pub(crate) fn parse_ts_parameter_types(p: &mut Parser) -> ParsedSyntax<Completed
 
 pub(crate) fn ts_parameter_types(p: &mut Parser) {
 	if p.at(T![<]) {
-		if let Some(ref mut ty) = ts_type_params(p) {
-			ty.err_if_not_ts(p, "type parameters can only be used in TypeScript files");
+		let mut guard = p.with_state(ParserState {
+			in_variance_position: false,
+			..p.state.clone()
+		});
+		if let Some(ref mut ty) = ts_type_params(&mut *guard) {
+			ty.err_if_not_ts(&mut *guard, "type parameters can only be used in TypeScript files");
 		}
 	}
 }
@@ -0,0 +1,96 @@
+//! TypeScript-only grammar productions shared between declarations and expressions.
+
+use super::binding::parse_binding;
+use crate::parser::ParserProgress;
+use crate::syntax::js_parse_error::expected_binding;
+use crate::{CompletedMarker, Parser, SyntaxKind::*, T};
+
+// test ts_type_parameters
+// type Id<T> = T;
+// function identity<T>(value: T): T { return value; }
+//
+// test ts_type_parameters_variance
+// type Mapper<in T, out U> = (value: T) => U;
+// interface Variant<in out T> { value: T }
+//
+// test_err ts_type_parameters_variance_not_allowed
+// function identity<in T>(value: T): T { return value; }
+/// Parses a `<T, U>`-style type parameter list into a `TS_TYPE_PARAMETER_LIST`.
+pub(crate) fn ts_type_params(p: &mut Parser) -> Option<CompletedMarker> {
+	if !p.at(T![<]) {
+		return None;
+	}
+
+	let m = p.start();
+	p.bump_any();
+
+	let mut progress = ParserProgress::default();
+	while !p.at(EOF) && !p.at(T![>]) {
+		progress.assert_progressing(p);
+		ts_type_param(p);
+
+		if !p.at(T![>]) {
+			p.expect_required(T![,]);
+		}
+	}
+
+	p.expect_required(T![>]);
+	Some(m.complete(p, TS_TYPE_PARAMETER_LIST))
+}
+
+/// Parses a single type parameter, including the TypeScript 4.7 `in`/`out` variance modifiers
+/// (`in` must precede `out` when both are present).
+fn ts_type_param(p: &mut Parser) {
+	let m = p.start();
+	let variance_start = p.cur_tok().range.start;
+	let mut has_variance_modifier = false;
+
+	if eat_in_modifier(p) {
+		has_variance_modifier = true;
+	}
+
+	if eat_out_modifier(p) {
+		has_variance_modifier = true;
+	}
+
+	if has_variance_modifier && !p.state.in_variance_position {
+		let variance_end = p.cur_tok().range.start;
+		let err = p
+			.err_builder(
+				"variance annotations are only permitted on type aliases, interfaces, and classes",
+			)
+			.primary(variance_start..variance_end, "");
+
+		p.error(err);
+	}
+
+	parse_binding(p).or_missing_with_error(p, expected_binding);
+
+	m.complete(p, TS_TYPE_PARAMETER);
+}
+
+/// Eats `in` as a variance modifier, but only if an identifier follows — `in` immediately
+/// followed by `,`, `>`, or `extends` is the parameter's *name*, not a modifier, so lookahead
+/// must confirm an identifier is next before consuming it. `in` is a reserved word, so the lexer
+/// already gives it its own token kind.
+fn eat_in_modifier(p: &mut Parser) -> bool {
+	if p.at(T![in]) && p.nth_at(1, T![ident]) {
+		p.bump_remap(T![in]);
+		true
+	} else {
+		false
+	}
+}
+
+/// Eats `out` as a variance modifier, with the same one-identifier lookahead as
+/// [eat_in_modifier]. Unlike `in`, `out` is not a reserved word, so the lexer tags it as a plain
+/// identifier token — it has to be matched by source text (same as the `override`/`async`
+/// contextual keyword checks elsewhere in this parser) and remapped once confirmed.
+fn eat_out_modifier(p: &mut Parser) -> bool {
+	if p.cur_src() == "out" && p.nth_at(1, T![ident]) {
+		p.bump_remap(T![out]);
+		true
+	} else {
+		false
+	}
+}